@@ -27,7 +27,7 @@
 use rmp_serde as rmps;
 
 mod time_series;
-pub use time_series::{TimeSeries, Timestamp, TimeValue};
+pub use time_series::{Aggregation, TimeSeries, Timestamp, TimeValue};
 
 /// Errors for this library
 #[derive(thiserror::Error, Debug)]
@@ -44,6 +44,13 @@ pub enum Error {
     /// Redis Error
     #[error(transparent)]
     Redis(#[from] redis::RedisError),
+    /// The bucket duration given to an aggregation query was zero.
+    #[error("Aggregation bucket duration must be non-zero")]
+    InvalidBucket,
+    /// An aggregation query with gap-filling would have produced more
+    /// buckets than the configured limit.
+    #[error("Aggregation would produce {0} buckets, exceeding the limit of {1}")]
+    TooManyBuckets(usize, usize),
 }
 
 /// The result type to use for the library
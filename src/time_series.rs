@@ -403,6 +403,183 @@ impl<T: DeserializeOwned> TimeSeries<T> {
 
 /////////////////////////////////////////////////////////////////////////////
 
+/// The aggregation function to apply when downsampling a time series into
+/// fixed-size time buckets.
+///
+/// These mirror the aggregators exposed by the native RedisTimeSeries
+/// module, but are computed client-side from the raw points in the zset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// The average (mean) of the values in the bucket.
+    Avg,
+    /// The minimum value in the bucket.
+    Min,
+    /// The maximum value in the bucket.
+    Max,
+    /// The sum of the values in the bucket.
+    Sum,
+    /// The number of values in the bucket.
+    Count,
+    /// The first value (by timestamp) in the bucket.
+    First,
+    /// The last value (by timestamp) in the bucket.
+    Last,
+    /// The (population) standard deviation of the values in the bucket.
+    StdDev,
+}
+
+/// Accumulates the running statistics for a single bucket as points are
+/// folded into it.
+#[derive(Debug, Clone, Copy)]
+struct BucketAccumulator {
+    count: u64,
+    sum: f64,
+    sum_sq: f64,
+    min: f64,
+    max: f64,
+    first: f64,
+    last: f64,
+}
+
+impl BucketAccumulator {
+    fn new(val: f64) -> Self {
+        Self {
+            count: 1,
+            sum: val,
+            sum_sq: val * val,
+            min: val,
+            max: val,
+            first: val,
+            last: val,
+        }
+    }
+
+    fn add(&mut self, val: f64) {
+        self.count += 1;
+        self.sum += val;
+        self.sum_sq += val * val;
+        self.min = self.min.min(val);
+        self.max = self.max.max(val);
+        self.last = val;
+    }
+
+    fn reduce(&self, agg: Aggregation) -> f64 {
+        match agg {
+            Aggregation::Avg => self.sum / self.count as f64,
+            Aggregation::Min => self.min,
+            Aggregation::Max => self.max,
+            Aggregation::Sum => self.sum,
+            Aggregation::Count => self.count as f64,
+            Aggregation::First => self.first,
+            Aggregation::Last => self.last,
+            Aggregation::StdDev => {
+                let mean = self.sum / self.count as f64;
+                ((self.sum_sq / self.count as f64) - mean * mean).max(0.0).sqrt()
+            },
+        }
+    }
+}
+
+/// The maximum number of buckets an `aggregate_range` call may produce when
+/// `fill_gaps` is set.
+///
+/// This guards against a caller picking a bucket duration that is tiny
+/// relative to the queried time span (or simply querying a huge span),
+/// which would otherwise try to materialize one `TimeValue` per bucket
+/// index, most of them `NaN`.
+const MAX_FILL_GAPS_BUCKETS: usize = 1_000_000;
+
+impl<T> TimeSeries<T>
+where
+    T: DeserializeOwned + Into<f64>,
+{
+    /// Gets values from a time range, aggregated/downsampled into fixed-size
+    /// time buckets.
+    ///
+    /// The raw points in `[ts1, ts2)` are pulled from the zset and folded
+    /// into buckets of width `bucket`, starting at
+    /// `(timestamp / bucket).floor() * bucket`. Each bucket is then reduced
+    /// to a single value using `agg`.
+    ///
+    /// If `fill_gaps` is true, buckets that contain no points are still
+    /// emitted, with a value of `NaN`, so that the result has one entry per
+    /// bucket across the full span of the data. Otherwise, empty buckets
+    /// are simply omitted from the result. Note that a small `bucket`
+    /// relative to the span of `[ts1, ts2)` can make the gap-filled result
+    /// very large; this is rejected with `Error::TooManyBuckets` once it
+    /// would exceed `MAX_FILL_GAPS_BUCKETS` entries.
+    ///
+    /// Returns `Error::InvalidBucket` if `bucket` is zero.
+    pub fn aggregate_range<S, U>(
+        &mut self,
+        ts1: S,
+        ts2: U,
+        bucket: Duration,
+        agg: Aggregation,
+        fill_gaps: bool,
+    ) -> Result<Vec<TimeValue<f64>>>
+    where
+        S: Into<Timestamp>,
+        U: Into<Timestamp>,
+    {
+        let bucket_secs = bucket.as_secs_f64();
+        if bucket_secs <= 0.0 {
+            return Err(crate::Error::InvalidBucket);
+        }
+        let pts = self.get_range(ts1, ts2)?;
+
+        let mut buckets: Vec<(i64, BucketAccumulator)> = Vec::new();
+        for pt in pts {
+            let val: f64 = pt.value.into();
+            let bucket_start = (pt.timestamp.as_f64() / bucket_secs).floor() as i64;
+
+            match buckets.last_mut() {
+                Some((start, acc)) if *start == bucket_start => acc.add(val),
+                _ => buckets.push((bucket_start, BucketAccumulator::new(val))),
+            }
+        }
+
+        let vret = if fill_gaps {
+            let mut vret = Vec::new();
+            if let (Some(&(first, _)), Some(&(last, _))) = (buckets.first(), buckets.last()) {
+                let bucket_count = (last - first + 1) as usize;
+                if bucket_count > MAX_FILL_GAPS_BUCKETS {
+                    return Err(crate::Error::TooManyBuckets(
+                        bucket_count,
+                        MAX_FILL_GAPS_BUCKETS,
+                    ));
+                }
+                let mut iter = buckets.into_iter().peekable();
+                for bucket_start in first..=last {
+                    let value = match iter.peek() {
+                        Some((start, _)) if *start == bucket_start => {
+                            iter.next().unwrap().1.reduce(agg)
+                        },
+                        _ => f64::NAN,
+                    };
+                    vret.push(TimeValue::with_timestamp(
+                        bucket_start as f64 * bucket_secs,
+                        value,
+                    ));
+                }
+            }
+            vret
+        }
+        else {
+            buckets
+                .into_iter()
+                .map(|(start, acc)| {
+                    TimeValue::with_timestamp(start as f64 * bucket_secs, acc.reduce(agg))
+                })
+                .collect()
+        };
+
+        Ok(vret)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -461,6 +638,33 @@ mod tests {
         assert_eq!(13, v[2].value);
     }
 
+    #[test]
+    fn test_aggregate_range() {
+        let mut series = TimeSeries::new(NAMESPACE, "aggregate").unwrap();
+        let _ = series.delete();
+
+        series.add(0.0, 1.0).unwrap();
+        series.add(1.0, 3.0).unwrap();
+        series.add(10.0, 5.0).unwrap();
+
+        let v = series
+            .aggregate_range(0.0, 20.0, Duration::from_secs(5), Aggregation::Avg, false)
+            .unwrap();
+        assert_eq!(2, v.len());
+        assert_eq!(0.0, v[0].timestamp.as_f64());
+        assert_eq!(2.0, v[0].value);
+        assert_eq!(10.0, v[1].timestamp.as_f64());
+        assert_eq!(5.0, v[1].value);
+
+        let v = series
+            .aggregate_range(0.0, 20.0, Duration::from_secs(5), Aggregation::Sum, true)
+            .unwrap();
+        assert_eq!(3, v.len());
+        assert_eq!(4.0, v[0].value);
+        assert!(v[1].value.is_nan());
+        assert_eq!(5.0, v[2].value);
+    }
+
     #[test]
     fn test_purge() {
         let mut series = TimeSeries::new(NAMESPACE, "purge").unwrap();